@@ -0,0 +1,61 @@
+#![cfg_attr(feature = "bench", feature(test))]
+#[cfg(feature = "bench")]
+extern crate test;
+
+/// A single dial move: direction (`L` or `R`) and distance.
+type Move = (char, i32);
+
+/// Parses each line into a `(direction, distance)` move, skipping blanks.
+fn parse_input(input: &str) -> Vec<Move> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let direction = line.chars().next().unwrap();
+            let distance: i32 = line[1..].parse().expect("Failed to parse number");
+            (direction, distance)
+        })
+        .collect()
+}
+
+/// Counts how many times the dial passes (or lands on) a multiple of 100,
+/// summed across every move.
+fn part2(moves: &[Move]) -> i32 {
+    // Start position of the dial
+    let mut position: i64 = 50;
+
+    // Counter for how many times we land on 0 during any click
+    let mut zero_count: i64 = 0;
+
+    for &(direction, distance) in moves {
+        let delta = match direction {
+            'L' => -i64::from(distance),
+            'R' => i64::from(distance),
+            _ => panic!("Unexpected direction: {}", direction),
+        };
+
+        zero_count += aoc_common::dial::crossings(position, delta, 100);
+        position = aoc_common::dial::advance(position, delta, 100);
+    }
+
+    zero_count as i32
+}
+
+fn main() {
+    // Read the puzzle input (see aoc_common::read_input for resolution order)
+    let input = aoc_common::read_input();
+    let moves = parse_input(&input);
+
+    println!("Password: {}", part2(&moves));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // L68 from the starting position 50: crosses 0 once
+    const TEST_INPUT: &str = "L68\n";
+
+    aoc_common::boilerplate!(input: TEST_INPUT, part2: (part2, 1));
+}
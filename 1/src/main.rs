@@ -0,0 +1,63 @@
+#![cfg_attr(feature = "bench", feature(test))]
+#[cfg(feature = "bench")]
+extern crate test;
+
+/// A single dial move: direction (`L` or `R`) and distance.
+type Move = (char, i32);
+
+/// Parses each line into a `(direction, distance)` move, skipping blanks.
+fn parse_input(input: &str) -> Vec<Move> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            // First character is the direction (L or R), the rest is the distance
+            let direction = line.chars().next().unwrap();
+            let distance: i32 = line[1..].parse().expect("Failed to parse number");
+            (direction, distance)
+        })
+        .collect()
+}
+
+/// Counts how many moves land exactly on 0.
+fn part1(moves: &[Move]) -> i32 {
+    // Start position of the dial
+    let mut position: i64 = 50;
+
+    // Counter for how many times we land on 0
+    let mut zero_count = 0;
+
+    for &(direction, distance) in moves {
+        let delta = match direction {
+            'L' => -i64::from(distance),
+            'R' => i64::from(distance),
+            _ => panic!("Unexpected direction: {}", direction),
+        };
+
+        position = aoc_common::dial::advance(position, delta, 100);
+
+        if position == 0 {
+            zero_count += 1;
+        }
+    }
+
+    zero_count
+}
+
+fn main() {
+    // Read the puzzle input (see aoc_common::read_input for resolution order)
+    let input = aoc_common::read_input();
+    let moves = parse_input(&input);
+
+    println!("Password: {}", part1(&moves));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "R50\nR50\n";
+
+    aoc_common::boilerplate!(input: TEST_INPUT, part1: (part1, 1));
+}
@@ -0,0 +1,50 @@
+#![cfg_attr(feature = "bench", feature(test))]
+#[cfg(feature = "bench")]
+extern crate test;
+
+/// An inclusive ID range parsed from one `start-end` entry.
+type Range = (u64, u64);
+
+/// Parses the comma-separated `start-end` ranges on the input line,
+/// skipping malformed ones.
+fn parse_input(input: &str) -> Vec<Range> {
+    input
+        .trim()
+        .split(',')
+        .filter_map(|range| {
+            let parts: Vec<&str> = range.split('-').collect();
+            if parts.len() != 2 {
+                return None; // Skip malformed ranges
+            }
+
+            let start: u64 = aoc_common::parse_num(parts[0]);
+            let end: u64 = aoc_common::parse_num(parts[1]);
+            Some((start, end))
+        })
+        .collect()
+}
+
+/// Sums the invalid IDs (a pattern repeated two or more times) across every range.
+fn part2(ranges: &[Range]) -> u64 {
+    ranges
+        .iter()
+        .map(|&(start, end)| aoc_common::invalid_ids::sum_invalid_ids(start, end, false))
+        .sum()
+}
+
+fn main() {
+    // Read the puzzle input (see aoc_common::read_input for resolution order)
+    let input = aoc_common::read_input();
+    let ranges = parse_input(&input);
+
+    println!("Sum of invalid IDs: {}", part2(&ranges));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "111-111";
+
+    aoc_common::boilerplate!(input: TEST_INPUT, part2: (part2, 111));
+}
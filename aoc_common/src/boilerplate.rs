@@ -0,0 +1,58 @@
+//! The `boilerplate!` macro generates the `#[test]` (and, behind the
+//! `bench` feature, `#[bench]`) wrappers that every solution binary in
+//! this workspace uses to check its `parse_input`/`part1`/`part2`
+//! functions against the documented sample input and answer.
+//!
+//! `#[bench]` requires the unstable `test` crate, so it's only compiled
+//! when the `bench` Cargo feature is enabled (`cargo +nightly test
+//! --features bench`); a plain `cargo test` on stable only gets the
+//! `#[test]` cases.
+
+/// Declares `#[test]`/`#[bench]` wrappers for a solution's `part1` and/or
+/// `part2` function against a sample `TEST_INPUT`.
+///
+/// ```ignore
+/// aoc_common::boilerplate!(input: TEST_INPUT, part1: (part1, 3));
+/// aoc_common::boilerplate!(input: TEST_INPUT, part2: (part2, 5));
+/// ```
+#[macro_export]
+macro_rules! boilerplate {
+    (input: $input:expr, part1: ($part1_fn:expr, $part1_expected:expr) $(,)?) => {
+        #[test]
+        fn test_part1() {
+            let parsed = parse_input($input);
+            assert_eq!($part1_fn(&parsed), $part1_expected);
+        }
+
+        #[cfg(feature = "bench")]
+        #[bench]
+        fn bench_part1(b: &mut test::Bencher) {
+            let parsed = parse_input($input);
+            b.iter(|| $part1_fn(&parsed));
+        }
+    };
+
+    (input: $input:expr, part2: ($part2_fn:expr, $part2_expected:expr) $(,)?) => {
+        #[test]
+        fn test_part2() {
+            let parsed = parse_input($input);
+            assert_eq!($part2_fn(&parsed), $part2_expected);
+        }
+
+        #[cfg(feature = "bench")]
+        #[bench]
+        fn bench_part2(b: &mut test::Bencher) {
+            let parsed = parse_input($input);
+            b.iter(|| $part2_fn(&parsed));
+        }
+    };
+
+    (
+        input: $input:expr,
+        part1: ($part1_fn:expr, $part1_expected:expr),
+        part2: ($part2_fn:expr, $part2_expected:expr) $(,)?
+    ) => {
+        $crate::boilerplate!(input: $input, part1: ($part1_fn, $part1_expected));
+        $crate::boilerplate!(input: $input, part2: ($part2_fn, $part2_expected));
+    };
+}
@@ -0,0 +1,63 @@
+//! A reusable modular-interval counter for "dial" puzzles: a position on a
+//! circular `0..modulus` track that moves by a signed delta, where the
+//! interesting quantity is how many multiples of `modulus` the move
+//! passes (or lands on).
+
+/// How many times a move of signed `delta` from `position` passes a
+/// multiple of `modulus`.
+///
+/// Implemented with `div_euclid` so negative deltas (moving left/backward)
+/// fall out of the same formula as positive ones (moving right/forward)
+/// by symmetry, rather than needing separate match arms per direction.
+/// Landing exactly on a multiple counts as a crossing when approached from
+/// below, but not when approached from above, matching the original
+/// per-direction formulas this replaces.
+pub fn crossings(position: i64, delta: i64, modulus: i64) -> i64 {
+    ((position + delta).div_euclid(modulus) - position.div_euclid(modulus)).abs()
+}
+
+/// The normalized position after moving `delta` from `position`, wrapped
+/// into `0..modulus`.
+pub fn advance(position: i64, delta: i64, modulus: i64) -> i64 {
+    (position + delta).rem_euclid(modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn landing_exactly_on_zero_from_below() {
+        // 95 -> 100: passes/lands on a multiple of 100 once
+        assert_eq!(crossings(95, 5, 100), 1);
+        assert_eq!(advance(95, 5, 100), 0);
+    }
+
+    #[test]
+    fn landing_exactly_on_zero_from_above() {
+        // 5 -> 0 moving left: matches the puzzle's asymmetric convention
+        // where approaching 0 from above doesn't count as a fresh crossing
+        assert_eq!(crossings(5, -5, 100), 0);
+        assert_eq!(advance(5, -5, 100), 0);
+    }
+
+    #[test]
+    fn multiple_full_wraps_in_one_move() {
+        // Three full laps plus a bit: crosses 100 three times
+        assert_eq!(crossings(50, 300, 100), 3);
+        assert_eq!(advance(50, 300, 100), 50);
+    }
+
+    #[test]
+    fn delta_larger_than_modulus_going_left() {
+        assert_eq!(crossings(50, -250, 100), 2);
+        assert_eq!(advance(50, -250, 100), 0);
+    }
+
+    #[test]
+    fn l68_from_50() {
+        // The documented example: L68 from position 50 crosses 0 once
+        assert_eq!(crossings(50, -68, 100), 1);
+        assert_eq!(advance(50, -68, 100), 82);
+    }
+}
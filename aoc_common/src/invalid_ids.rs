@@ -0,0 +1,76 @@
+//! Closed-form enumeration of "invalid IDs": numbers whose decimal digits
+//! are a base pattern repeated two or more times (e.g. `11`, `6464`,
+//! `123123`). Scanning every integer in a range is infeasible once the
+//! range spans billions, so instead we generate the invalid IDs directly
+//! from their pattern and length.
+
+/// Number of decimal digits in `v`.
+fn digit_count(v: u64) -> u32 {
+    v.to_string().len() as u32
+}
+
+/// Divisors `d` of `n` with `1 <= d < n`, i.e. valid pattern lengths for a
+/// pattern repeated `k = n / d >= 2` times.
+fn proper_divisors(n: u32) -> Vec<u32> {
+    (1..n).filter(|d| n.is_multiple_of(*d)).collect()
+}
+
+/// `sum_{i=0}^{k-1} 10^(i*d)`, the multiplier that turns a `d`-digit base
+/// pattern into the value of that pattern repeated `k` times.
+fn repunit_multiplier(d: u32, k: u32) -> u64 {
+    (0..k).map(|i| 10u64.pow(i * d)).sum()
+}
+
+/// Whether `pattern` (a string of decimal digits) is *primitive*, i.e. not
+/// itself a shorter pattern repeated. `"11"` is not primitive (it's `"1"`
+/// repeated); `"12"` is.
+fn is_primitive(pattern: &str) -> bool {
+    let d = pattern.len() as u32;
+    proper_divisors(d).into_iter().all(|e| {
+        let e = e as usize;
+        let base = &pattern.as_bytes()[..e];
+        !pattern.as_bytes().chunks(e).all(|chunk| chunk == base)
+    })
+}
+
+/// Sums every invalid ID in `[lo, hi]`.
+///
+/// When `exactly_two` is `true`, a number counts only if its digits split
+/// exactly in half into two identical halves (the original "repeated
+/// twice" rule). When `false`, a number counts if its digits are *any*
+/// pattern repeated two or more times; patterns are restricted to
+/// primitive ones so each invalid number is generated exactly once, via
+/// its minimal period, instead of once per divisor that happens to match.
+pub fn sum_invalid_ids(lo: u64, hi: u64, exactly_two: bool) -> u64 {
+    let lo_digits = digit_count(lo);
+    let hi_digits = digit_count(hi);
+    let mut total = 0u64;
+
+    for n in lo_digits..=hi_digits {
+        let divisors = if exactly_two {
+            if n % 2 == 0 { vec![n / 2] } else { vec![] }
+        } else {
+            proper_divisors(n)
+        };
+
+        for d in divisors {
+            let k = n / d;
+            let multiplier = repunit_multiplier(d, k);
+            let base_lo: u64 = if d == 1 { 1 } else { 10u64.pow(d - 1) };
+            let base_hi: u64 = 10u64.pow(d) - 1;
+
+            for base in base_lo..=base_hi {
+                if !exactly_two && !is_primitive(&base.to_string()) {
+                    continue;
+                }
+
+                let value = base * multiplier;
+                if value >= lo && value <= hi {
+                    total += value;
+                }
+            }
+        }
+    }
+
+    total
+}
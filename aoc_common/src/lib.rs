@@ -0,0 +1,98 @@
+//! Shared helpers for the Advent of Code solutions in this workspace:
+//! input loading and small parsing utilities used by more than one binary.
+
+use std::fs;
+use std::io::{self, Read};
+use std::ops::{Add, Mul};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+mod boilerplate;
+pub mod dial;
+pub mod invalid_ids;
+
+/// Reads the puzzle input.
+///
+/// Checked in order: a positional CLI argument (`solution path/to/input`,
+/// or `solution -` to pipe input in via stdin), then the `AOC_INPUT`
+/// environment variable, then `input.txt` in the current directory. This
+/// lets a solution be pointed at a different file, or composed in a shell
+/// pipeline with `solution - < input.txt`, without recompiling or renaming
+/// files for each day's input. Panics with the attempted path and the
+/// underlying IO error if the file can't be read, instead of a bare
+/// `expect` message.
+pub fn read_input() -> String {
+    match std::env::args().nth(1) {
+        Some(arg) if arg == "-" => read_stdin(),
+        Some(path) => read_file(Path::new(&path)),
+        None => read_file(&input_path()),
+    }
+}
+
+/// Reads the input for a specific day from `inputs/dayNN`.
+pub fn read_input_for_day(day: usize) -> String {
+    read_file(&PathBuf::from(format!("inputs/day{day:02}")))
+}
+
+fn input_path() -> PathBuf {
+    match std::env::var("AOC_INPUT") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => PathBuf::from("input.txt"),
+    }
+}
+
+fn read_file(path: &Path) -> String {
+    fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read input file '{}': {}", path.display(), e))
+}
+
+fn read_stdin() -> String {
+    let mut buf = String::new();
+    io::stdin()
+        .read_to_string(&mut buf)
+        .unwrap_or_else(|e| panic!("Failed to read input from stdin: {}", e));
+    buf
+}
+
+/// Parses one `u64` per line, skipping blank lines.
+pub fn parse_nums(s: &str) -> Vec<u64> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().expect("Invalid number"))
+        .collect()
+}
+
+/// Parses a single line of comma-separated `u64`s.
+pub fn parse_nums_comma(s: &str) -> Vec<u64> {
+    s.trim()
+        .split(',')
+        .map(|part| part.trim().parse().expect("Invalid number"))
+        .collect()
+}
+
+/// Parses a single unsigned integer, with different behavior depending on
+/// the build profile.
+///
+/// Debug builds go through `str::parse`, so a malformed puzzle input fails
+/// loudly with the offending string. Release builds skip that validation
+/// and fold the ASCII bytes directly, since the invalid-ID solutions call
+/// this on the hot path while scanning ranges spanning billions of values
+/// and the full `FromStr` machinery isn't worth paying for there.
+pub fn parse_num<T>(s: &str) -> T
+where
+    T: FromStr + From<u8> + Add<Output = T> + Mul<Output = T> + Copy,
+    T::Err: std::fmt::Debug,
+{
+    #[cfg(debug_assertions)]
+    {
+        s.parse().unwrap_or_else(|_| panic!("Invalid number {s}"))
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        let ten = T::from(10u8);
+        s.bytes()
+            .fold(T::from(0u8), |acc, b| acc * ten + T::from(b - b'0'))
+    }
+}